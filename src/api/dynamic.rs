@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::{
+        resource::{KubeObject, ObjectMeta, TypeMeta},
+        typed::Api,
+        RawApi,
+    },
+    client::APIClient,
+};
+
+/// A description of a Kubernetes resource kind, discoverable at runtime
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ApiResource {
+    /// API group, empty string for the core group
+    pub group: String,
+    /// API version
+    pub version: String,
+    /// Kind, in PascalCase
+    pub kind: String,
+    /// Plural name used in the resource's URL
+    pub plural: String,
+    /// Whether this resource is namespaced
+    pub namespaced: bool,
+}
+
+impl<K> Api<K> {
+    /// Build an `Api` for all namespaces from a runtime-discovered `ApiResource`
+    pub fn all_with(client: APIClient, ar: &ApiResource) -> Self {
+        Self {
+            api: RawApi::customResource(&ar.plural)
+                .group(&ar.group)
+                .version(&ar.version),
+            client,
+            phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Build a namespaced `Api` from a runtime-discovered `ApiResource`
+    pub fn namespaced_with(client: APIClient, ns: &str, ar: &ApiResource) -> Self {
+        Self::all_with(client, ar).within(ns)
+    }
+}
+
+/// A generic Kubernetes object, typed only by its `spec` and `status`
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Object<T, U> {
+    /// The type fields, not always present
+    #[serde(flatten)]
+    pub types: TypeMeta,
+
+    /// Standard object metadata
+    #[serde(default)]
+    pub metadata: ObjectMeta,
+
+    /// The custom resource spec
+    pub spec: T,
+
+    /// The custom resource status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<U>,
+}
+
+impl<T, U> KubeObject for Object<T, U> {
+    fn meta(&self) -> &ObjectMeta {
+        &self.metadata
+    }
+}
+
+/// A Kubernetes object whose body isn't known to be spec/status-shaped
+///
+/// Unlike `Object<T, U>`, this doesn't require a top-level `spec` key, so it also
+/// covers resources like `ConfigMap`, `Secret` or `Event` that aren't spec/status
+/// objects.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DynamicObject {
+    /// The type fields, not always present
+    #[serde(flatten)]
+    pub types: TypeMeta,
+
+    /// Standard object metadata
+    #[serde(default)]
+    pub metadata: ObjectMeta,
+
+    /// Everything else in the object, untyped
+    #[serde(flatten)]
+    pub data: serde_json::Value,
+}
+
+impl KubeObject for DynamicObject {
+    fn meta(&self) -> &ObjectMeta {
+        &self.metadata
+    }
+}
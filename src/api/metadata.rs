@@ -0,0 +1,42 @@
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::marker::PhantomData;
+
+use crate::api::resource::{KubeObject, ObjectMeta, TypeMeta};
+
+/// A thin `TypeMeta` + `ObjectMeta` view of a resource, with no `spec`/`status`
+///
+/// `K` only keeps this paired up with the matching `Api<K>`; none of its fields are
+/// read or stored.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PartialObjectMeta<K> {
+    /// The type fields, not always present
+    #[serde(flatten)]
+    pub types: TypeMeta,
+
+    /// Standard object metadata
+    #[serde(default)]
+    pub metadata: ObjectMeta,
+
+    #[serde(skip)]
+    phantom: PhantomData<K>,
+}
+
+impl<K> KubeObject for PartialObjectMeta<K> {
+    fn meta(&self) -> &ObjectMeta {
+        &self.metadata
+    }
+}
+
+impl<K> From<PartialObjectMeta<K>> for ObjectMeta {
+    fn from(partial: PartialObjectMeta<K>) -> Self {
+        partial.metadata
+    }
+}
+
+/// `Accept` header requesting a `PartialObjectMetadata` view of a single resource
+pub(crate) const ACCEPT_METADATA: &str =
+    "application/json;as=PartialObjectMetadata;g=meta.k8s.io;v=v1";
+
+/// `Accept` header requesting a `PartialObjectMetadataList` view of a resource collection
+pub(crate) const ACCEPT_METADATA_LIST: &str =
+    "application/json;as=PartialObjectMetadataList;g=meta.k8s.io;v=v1";
@@ -0,0 +1,215 @@
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::{
+        typed::{Api, Patch},
+        DeleteParams, PatchParams, PostParams,
+    },
+    client::Status,
+    Result,
+};
+
+/// Marker trait for resources that expose the `log` subresource
+pub trait LoggingObject {}
+
+impl LoggingObject for k8s_openapi::api::core::v1::Pod {}
+
+/// Parameters for `Api<Pod>::logs`/`log_stream`
+#[derive(Clone, Debug, Default)]
+pub struct LogParams {
+    /// The container to get logs from, required when the pod has more than one
+    pub container: Option<String>,
+    /// Follow the log stream, used by [`Api::log_stream`]
+    pub follow: bool,
+    /// Only return logs newer than this many seconds
+    pub since_seconds: Option<i64>,
+    /// If set, only return this many lines from the end of the log
+    pub tail_lines: Option<i64>,
+    /// Include timestamps on each line
+    pub timestamps: bool,
+    /// Return previous terminated container logs
+    pub previous: bool,
+}
+
+impl<K: LoggingObject> Api<K> {
+    /// Fetch the current logs for a pod, buffered into a single `String`
+    pub async fn logs(&self, name: &str, lp: &LogParams) -> Result<String> {
+        let req = self.api.subresource_get("log", name, lp)?;
+        self.client.request_text(req).await
+    }
+
+    /// Follow the logs for a pod, yielding raw chunks of log bytes as they arrive
+    pub async fn log_stream(
+        &self,
+        name: &str,
+        lp: &LogParams,
+    ) -> Result<impl Stream<Item = Result<bytes::Bytes>>> {
+        let mut lp = lp.clone();
+        lp.follow = true;
+        let req = self.api.subresource_get("log", name, &lp)?;
+        self.client.request_text_stream(req).await
+    }
+}
+
+/// Parameters for `exec`/`attach`
+#[derive(Clone, Debug, Default)]
+pub struct AttachParams {
+    /// The container to attach to, required when the pod has more than one
+    pub container: Option<String>,
+    /// Attach `stdin`
+    pub stdin: bool,
+    /// Attach `stdout`
+    pub stdout: bool,
+    /// Attach `stderr`
+    pub stderr: bool,
+    /// Allocate a TTY
+    pub tty: bool,
+}
+
+/// A handle to a live `exec`/`attach` session, demultiplexed into stdin/stdout/stderr
+pub struct AttachedProcess {
+    stdin: Option<Box<dyn tokio::io::AsyncWrite + Send + Unpin>>,
+    stdout: Option<Box<dyn tokio::io::AsyncRead + Send + Unpin>>,
+    stderr: Option<Box<dyn tokio::io::AsyncRead + Send + Unpin>>,
+}
+
+impl AttachedProcess {
+    /// Take the writer for the process' `stdin`, if `AttachParams::stdin` was set
+    pub fn take_stdin(&mut self) -> Option<impl tokio::io::AsyncWrite + Send + Unpin> {
+        self.stdin.take()
+    }
+
+    /// Take the reader for the process' `stdout`, if `AttachParams::stdout` was set
+    pub fn take_stdout(&mut self) -> Option<impl tokio::io::AsyncRead + Send + Unpin> {
+        self.stdout.take()
+    }
+
+    /// Take the reader for the process' `stderr`, if `AttachParams::stderr` was set
+    pub fn take_stderr(&mut self) -> Option<impl tokio::io::AsyncRead + Send + Unpin> {
+        self.stderr.take()
+    }
+}
+
+/// A port-forwarding session, holding independent read/write halves for each forwarded port
+pub struct Portforwarder {
+    streams: std::collections::HashMap<u16, (
+        Box<dyn tokio::io::AsyncRead + Send + Unpin>,
+        Box<dyn tokio::io::AsyncWrite + Send + Unpin>,
+    )>,
+}
+
+impl Portforwarder {
+    /// Take the duplex stream for one of the forwarded `ports`, if present
+    pub fn take_stream(
+        &mut self,
+        port: u16,
+    ) -> Option<(
+        impl tokio::io::AsyncRead + Send + Unpin,
+        impl tokio::io::AsyncWrite + Send + Unpin,
+    )> {
+        self.streams.remove(&port)
+    }
+}
+
+#[cfg(feature = "ws")]
+impl<K: LoggingObject> Api<K> {
+    /// Execute a command in a container, attaching to its stdin/stdout/stderr
+    pub async fn exec<I, T>(&self, name: &str, command: I, ap: &AttachParams) -> Result<AttachedProcess>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        let command = command.into_iter().map(Into::into).collect::<Vec<_>>();
+        let req = self.api.subresource_upgrade("exec", name, ap, Some(&command))?;
+        self.client.connect_ws(req).await
+    }
+
+    /// Attach to a running container's stdin/stdout/stderr
+    pub async fn attach(&self, name: &str, ap: &AttachParams) -> Result<AttachedProcess> {
+        let req = self
+            .api
+            .subresource_upgrade::<&str>("attach", name, ap, None)?;
+        self.client.connect_ws(req).await
+    }
+
+    /// Forward one or more container ports to locally addressable duplex streams
+    pub async fn portforward(&self, name: &str, ports: &[u16]) -> Result<Portforwarder> {
+        let req = self.api.subresource_upgrade_portforward(name, ports)?;
+        self.client.connect_ws(req).await
+    }
+}
+
+/// A minimal view of the `/scale` subresource, shared by every scalable workload kind
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Scale {
+    /// The desired replica count
+    pub spec: ScaleSpec,
+    /// The observed replica count, absent until the controller has reported it
+    #[serde(default)]
+    pub status: Option<ScaleStatus>,
+}
+
+/// The `spec` half of [`Scale`]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ScaleSpec {
+    /// Desired number of replicas
+    #[serde(default)]
+    pub replicas: i32,
+}
+
+/// The `status` half of [`Scale`]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ScaleStatus {
+    /// Actual number of observed replicas
+    #[serde(default)]
+    pub replicas: i32,
+    /// Label selector for the pods backing this scale target, as a query string
+    #[serde(default)]
+    pub selector: Option<String>,
+}
+
+/// Parameters for `Api::evict`
+#[derive(Clone, Debug, Default)]
+pub struct EvictParams {
+    /// Deletion options to apply to the evicted pod
+    pub delete_options: Option<DeleteParams>,
+}
+
+impl<K> Api<K> {
+    /// Fetch the `/scale` subresource for a scalable resource (e.g. a `Deployment`)
+    pub async fn get_scale(&self, name: &str) -> Result<Scale> {
+        let req = self.api.subresource_get("scale", name, &())?;
+        self.client.request::<Scale>(req).await
+    }
+
+    /// Patch the `/scale` subresource, e.g. to change the replica count
+    pub async fn patch_scale(&self, name: &str, pp: &PatchParams, patch: &Patch<Scale>) -> Result<Scale> {
+        let req = self
+            .api
+            .subresource_patch("scale", name, pp, patch.content_type(), patch.serialize()?)?;
+        self.client.request::<Scale>(req).await
+    }
+
+    /// Replace the `/scale` subresource wholesale
+    pub async fn replace_scale(&self, name: &str, pp: &PostParams, data: Scale) -> Result<Scale> {
+        let req = self
+            .api
+            .subresource_replace("scale", name, pp, serde_json::to_vec(&data)?)?;
+        self.client.request::<Scale>(req).await
+    }
+
+    /// Evict a pod, honouring the grace period in `ep.delete_options`
+    pub async fn evict(&self, name: &str, ep: &EvictParams) -> Result<Status> {
+        let eviction = serde_json::json!({
+            "apiVersion": "policy/v1",
+            "kind": "Eviction",
+            "metadata": { "name": name },
+            "deleteOptions": ep.delete_options,
+        });
+        let req = self
+            .api
+            .subresource_post("eviction", name, serde_json::to_vec(&eviction)?)?;
+        self.client.request::<Status>(req).await
+    }
+}
@@ -7,6 +7,7 @@ use std::marker::PhantomData;
 
 use crate::{
     api::{
+        metadata::{PartialObjectMeta, ACCEPT_METADATA, ACCEPT_METADATA_LIST},
         resource::{KubeObject, ObjectList, WatchEvent},
         DeleteParams, ListParams, PatchParams, PostParams, RawApi,
     },
@@ -14,6 +15,41 @@ use crate::{
     Result,
 };
 
+/// A patch to apply to a resource, carrying its own serialization strategy
+#[derive(Clone, Debug)]
+pub enum Patch<S> {
+    /// JSON Patch (`application/json-patch+json`)
+    Json(json_patch::Patch),
+    /// JSON Merge Patch (`application/merge-patch+json`)
+    Merge(serde_json::Value),
+    /// Strategic Merge Patch (`application/strategic-merge-patch+json`)
+    Strategic(serde_json::Value),
+    /// Server-Side Apply of a typed object (`application/apply-patch+yaml`)
+    Apply(S),
+}
+
+impl<S: Serialize> Patch<S> {
+    /// The `Content-Type` header value the apiserver expects for this variant
+    pub(crate) fn content_type(&self) -> &'static str {
+        match self {
+            Self::Json(_) => "application/json-patch+json",
+            Self::Merge(_) => "application/merge-patch+json",
+            Self::Strategic(_) => "application/strategic-merge-patch+json",
+            Self::Apply(_) => "application/apply-patch+yaml",
+        }
+    }
+
+    /// Serialize this patch to the bytes expected for its content type
+    pub(crate) fn serialize(&self) -> Result<Vec<u8>> {
+        Ok(match self {
+            Self::Json(patch) => serde_json::to_vec(patch)?,
+            Self::Merge(patch) => serde_json::to_vec(patch)?,
+            Self::Strategic(patch) => serde_json::to_vec(patch)?,
+            Self::Apply(patch) => serde_yaml::to_string(patch)?.into_bytes(),
+        })
+    }
+}
+
 /// Compatibility trait to allow posting both untyped (raw `Vec<u8>`) and typed objects
 ///
 /// Should not be implemented or used by library consumers.
@@ -84,6 +120,30 @@ where
         self.client.request::<K>(req).await
     }
 
+    /// Get a named resource, returning `None` rather than erroring if it does not exist
+    pub async fn get_opt(&self, name: &str) -> Result<Option<K>> {
+        let req = self.api.get(name)?;
+        self.client.request_opt::<K>(req).await
+    }
+
+    /// Get only the `TypeMeta`/`ObjectMeta` of a named resource
+    pub async fn get_metadata(&self, name: &str) -> Result<PartialObjectMeta<K>> {
+        let mut req = self.api.get(name)?;
+        req.headers_mut()
+            .insert(http::header::ACCEPT, ACCEPT_METADATA.parse().unwrap());
+        self.client.request::<PartialObjectMeta<K>>(req).await
+    }
+
+    /// List only the `TypeMeta`/`ObjectMeta` of resources matching `lp`
+    pub async fn list_metadata(&self, lp: &ListParams) -> Result<ObjectList<PartialObjectMeta<K>>> {
+        let mut req = self.api.list(&lp)?;
+        req.headers_mut()
+            .insert(http::header::ACCEPT, ACCEPT_METADATA_LIST.parse().unwrap());
+        self.client
+            .request::<ObjectList<PartialObjectMeta<K>>>(req)
+            .await
+    }
+
     pub async fn create<S: SerializeKubeObject<K>>(&self, pp: &PostParams, data: S) -> Result<K> {
         let req = self.api.create(&pp, data.serialize_kube_object()?)?;
         self.client.request::<K>(req).await
@@ -94,20 +154,51 @@ where
         self.client.request_status::<K>(req).await
     }
 
+    /// Delete a named resource, returning `None` rather than erroring if it does not exist
+    pub async fn delete_opt(&self, name: &str, dp: &DeleteParams) -> Result<Option<Either<K, Status>>> {
+        let req = self.api.delete(name, &dp)?;
+        self.client.request_status_opt::<K>(req).await
+    }
+
     pub async fn list(&self, lp: &ListParams) -> Result<ObjectList<K>> {
         let req = self.api.list(&lp)?;
         self.client.request::<ObjectList<K>>(req).await
     }
 
+    /// List resources in a collection, auto-draining the `continue` token
+    pub fn list_all(&self, lp: &ListParams) -> impl Stream<Item = Result<K>> + '_ {
+        enum State {
+            Next(ListParams),
+            Done,
+        }
+        futures::stream::unfold(State::Next(lp.clone()), move |state| async move {
+            let lp = match state {
+                State::Next(lp) => lp,
+                State::Done => return None,
+            };
+            match self.list(&lp).await {
+                Ok(list) => {
+                    let next_state = match next_continue_token(list.metadata.continue_) {
+                        Some(cont) => State::Next(lp.continue_token(&cont)),
+                        None => State::Done,
+                    };
+                    Some((futures::stream::iter(list.items.into_iter().map(Ok)), next_state))
+                }
+                Err(e) => Some((futures::stream::iter(vec![Err(e)]), State::Done)),
+            }
+        })
+        .flatten()
+    }
+
     pub async fn delete_collection(&self, lp: &ListParams) -> Result<Either<ObjectList<K>, Status>> {
         let req = self.api.delete_collection(&lp)?;
         self.client.request_status::<ObjectList<K>>(req).await
     }
 
-    /// Deprecated to make way for a type-safe variant
-    #[deprecated(note = "not type-safe, use `RawApi` instead for now")]
-    pub async fn patch(&self, name: &str, pp: &PatchParams, patch: Vec<u8>) -> Result<K> {
-        let req = self.api.patch(name, &pp, patch)?;
+    pub async fn patch<S: Serialize>(&self, name: &str, pp: &PatchParams, patch: &Patch<S>) -> Result<K> {
+        let req = self
+            .api
+            .patch(name, &pp, patch.content_type(), patch.serialize()?)?;
         self.client.request::<K>(req).await
     }
 
@@ -121,6 +212,17 @@ where
         self.client.request::<K>(req).await
     }
 
+    /// Replace a named resource, returning `None` rather than erroring if it does not exist
+    pub async fn replace_opt<S: SerializeKubeObject<K>>(
+        &self,
+        name: &str,
+        pp: &PostParams,
+        data: S,
+    ) -> Result<Option<K>> {
+        let req = self.api.replace(name, &pp, data.serialize_kube_object()?)?;
+        self.client.request_opt::<K>(req).await
+    }
+
     pub async fn watch(&self, lp: &ListParams, version: &str) -> Result<impl Stream<Item = WatchEvent<K>>> {
         let req = self.api.watch(&lp, &version)?;
         self.client
@@ -147,3 +249,29 @@ where
 }
 
 // all other native impls in openapi.rs
+
+/// Whether `list_all` should keep paging, and with which token, given the
+/// `metadata.continue` of the page just fetched
+fn next_continue_token(continue_: Option<String>) -> Option<String> {
+    continue_.filter(|c| !c.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::next_continue_token;
+
+    #[test]
+    fn keeps_paging_on_a_nonempty_continue_token() {
+        assert_eq!(next_continue_token(Some("abc".to_string())), Some("abc".to_string()));
+    }
+
+    #[test]
+    fn stops_paging_when_continue_token_is_absent() {
+        assert_eq!(next_continue_token(None), None);
+    }
+
+    #[test]
+    fn stops_paging_when_continue_token_is_empty() {
+        assert_eq!(next_continue_token(Some(String::new())), None);
+    }
+}
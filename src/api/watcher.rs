@@ -0,0 +1,156 @@
+use std::time::Duration;
+
+use futures::{Stream, StreamExt};
+use rand::Rng;
+use serde::de::DeserializeOwned;
+
+use crate::{
+    api::{
+        resource::{KubeObject, WatchEvent},
+        typed::Api,
+        ListParams,
+    },
+    Result,
+};
+
+/// Backoff before re-listing after a `list`/`watch` error that isn't a `410 Gone`
+const RELIST_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Jittered delay before reopening a watch connection that closed (whether cleanly,
+/// e.g. the apiserver's periodic watch timeout, or via a `410 Gone`), so many
+/// controllers reconnecting at once — like every watcher in the cluster after an
+/// apiserver restart — don't all hit the apiserver in the same instant.
+fn reconnect_jitter() -> Duration {
+    Duration::from_millis(rand::thread_rng().gen_range(0..RELIST_BACKOFF.as_millis() as u64))
+}
+
+/// A desync-free, higher-level event emitted by [`watcher`]
+#[derive(Clone, Debug)]
+pub enum Event<K> {
+    /// The full, current state of the collection, emitted on (re-)list
+    Restarted(Vec<K>),
+    /// An object was added or updated
+    Applied(K),
+    /// An object was deleted
+    Deleted(K),
+}
+
+/// Watch a resource collection, transparently tracking `resourceVersion` and
+/// re-listing whenever the watch desyncs
+pub fn watcher<K>(api: Api<K>, lp: ListParams) -> impl Stream<Item = Result<Event<K>>>
+where
+    K: Clone + DeserializeOwned + KubeObject + Send + 'static,
+{
+    enum State {
+        /// Need to (re-)list before we have a `resourceVersion` to watch from
+        Relist,
+        /// Watching from a known `resourceVersion`
+        Watching(String),
+    }
+
+    futures::stream::unfold(State::Relist, move |state| {
+        let api = api.clone();
+        let lp = lp.clone();
+        async move {
+            match state {
+                State::Relist => match api.list(&lp).await {
+                    Ok(list) => {
+                        let version = list.metadata.resource_version.clone().unwrap_or_default();
+                        let items = list.items;
+                        Some((
+                            vec![Ok(Event::Restarted(items))],
+                            State::Watching(version),
+                        ))
+                    }
+                    Err(e) => {
+                        tokio::time::sleep(RELIST_BACKOFF).await;
+                        Some((vec![Err(e)], State::Relist))
+                    }
+                },
+                State::Watching(version) => match api.watch(&lp, &version).await {
+                    Ok(stream) => {
+                        let mut stream = Box::pin(stream);
+                        let mut events = Vec::new();
+                        let mut next_version = version.clone();
+                        let mut gone = false;
+                        while let Some(ev) = stream.next().await {
+                            match ev {
+                                WatchEvent::Added(obj) | WatchEvent::Modified(obj) => {
+                                    next_version = advance_resource_version(
+                                        &next_version,
+                                        obj.meta().resource_version.as_deref(),
+                                    );
+                                    events.push(Ok(Event::Applied(obj)));
+                                }
+                                WatchEvent::Deleted(obj) => {
+                                    next_version = advance_resource_version(
+                                        &next_version,
+                                        obj.meta().resource_version.as_deref(),
+                                    );
+                                    events.push(Ok(Event::Deleted(obj)));
+                                }
+                                WatchEvent::Bookmark(bm) => {
+                                    next_version = advance_resource_version(
+                                        &next_version,
+                                        Some(&bm.resource_version),
+                                    );
+                                }
+                                WatchEvent::Error(status) => {
+                                    // A 410 Gone means our resourceVersion expired server-side;
+                                    // anything else is a genuine error we still want to surface.
+                                    if status.reason.as_deref() == Some("Gone") {
+                                        gone = true;
+                                    } else {
+                                        events.push(Err(status.into()));
+                                    }
+                                    break;
+                                }
+                            }
+                        }
+                        let state = if gone {
+                            State::Relist
+                        } else {
+                            State::Watching(next_version)
+                        };
+                        tokio::time::sleep(reconnect_jitter()).await;
+                        Some((events, state))
+                    }
+                    Err(e) => {
+                        tokio::time::sleep(RELIST_BACKOFF).await;
+                        Some((vec![Err(e)], State::Relist))
+                    }
+                },
+            }
+        }
+    })
+    .flat_map(|events| futures::stream::iter(events))
+}
+
+/// Pick the `resourceVersion` to resume a watch from, keeping `current` if the
+/// candidate is missing or empty
+fn advance_resource_version(current: &str, candidate: Option<&str>) -> String {
+    match candidate {
+        Some(rv) if !rv.is_empty() => rv.to_string(),
+        _ => current.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::advance_resource_version;
+
+    #[test]
+    fn advances_on_a_fresh_resource_version() {
+        assert_eq!(advance_resource_version("100", Some("101")), "101");
+    }
+
+    #[test]
+    fn keeps_current_version_when_candidate_is_missing() {
+        assert_eq!(advance_resource_version("100", None), "100");
+    }
+
+    #[test]
+    fn keeps_current_version_when_candidate_is_empty() {
+        assert_eq!(advance_resource_version("100", Some("")), "100");
+    }
+}